@@ -0,0 +1,243 @@
+use std::sync::Arc;
+
+use crate::cell::{ArcCell, Cell, CellBuilder, CellParser, TonCellError};
+
+/// A dictionary key: the big-endian bit sequence indexing a value in a
+/// `HashmapE n X`. Always exactly `n` bits long for any key belonging to the
+/// same dictionary.
+pub type DictKey = Vec<bool>;
+
+/// Builds the cell tree for a non-empty `Hashmap n X` (the payload referenced by
+/// the single `Maybe` bit of a `HashmapE n X`). `entries` must carry distinct keys
+/// that are all exactly `key_len` bits long (a repeated key is an error, not
+/// silently deduplicated); `write_value` serializes one value into the leaf cell
+/// it ends up in.
+///
+/// The label for every edge is always encoded with the shortest of the three
+/// `HmLabel` variants (`hml_short`/`hml_long`/`hml_same`), matching the canonical
+/// encoding real TVM dictionaries use, so a dictionary rebuilt from its entries
+/// hashes identically to the on-chain original.
+pub fn build_hashmap<V>(
+    entries: Vec<(DictKey, V)>,
+    key_len: usize,
+    write_value: &impl Fn(&V, &mut CellBuilder) -> Result<(), TonCellError>,
+) -> Result<ArcCell, TonCellError> {
+    if entries.is_empty() {
+        return Err(TonCellError::InternalError(
+            "cannot build an empty Hashmap".to_string(),
+        ));
+    }
+    let label = common_prefix(entries.iter().map(|(key, _)| key.as_slice()));
+    let mut builder = CellBuilder::new();
+    write_label(&mut builder, &label, key_len)?;
+
+    let stripped: Vec<(DictKey, V)> = entries
+        .into_iter()
+        .map(|(key, value)| (key[label.len()..].to_vec(), value))
+        .collect();
+
+    if key_len - label.len() == 0 {
+        // hmn_leaf#_ {X:Type} value:X = HashmapNode 0 X;
+        if stripped.len() > 1 {
+            return Err(TonCellError::InternalError(
+                "cannot build a Hashmap with duplicate keys".to_string(),
+            ));
+        }
+        let (_, value) = stripped.into_iter().next().expect("non-empty entries");
+        write_value(&value, &mut builder)?;
+    } else {
+        // hmn_fork#_ {n:#} left:^(Hashmap n X) right:^(Hashmap n X) = HashmapNode (n+1) X;
+        let (left, right): (Vec<_>, Vec<_>) = stripped.into_iter().partition(|(key, _)| !key[0]);
+        let next_len = key_len - label.len() - 1;
+        let left_cell = build_hashmap(drop_selector_bit(left), next_len, write_value)?;
+        let right_cell = build_hashmap(drop_selector_bit(right), next_len, write_value)?;
+        builder.store_reference(&left_cell)?;
+        builder.store_reference(&right_cell)?;
+    }
+    Ok(Arc::new(builder.build()?))
+}
+
+/// Reads back every `(key, value)` pair out of a non-empty `Hashmap key_len X` cell
+/// tree, as produced by [`build_hashmap`] or encountered on-chain.
+pub fn parse_hashmap<V>(
+    cell: &Cell,
+    key_len: usize,
+    read_value: &impl Fn(&mut CellParser) -> Result<V, TonCellError>,
+) -> Result<Vec<(DictKey, V)>, TonCellError> {
+    let mut parser = cell.parser();
+    let label = read_label(&mut parser, key_len)?;
+    let remaining = key_len - label.len();
+
+    if remaining == 0 {
+        let value = read_value(&mut parser)?;
+        return Ok(vec![(label, value)]);
+    }
+
+    let mut result = Vec::new();
+    for (selector, child) in [(false, cell.reference(0)?), (true, cell.reference(1)?)] {
+        for (suffix, value) in parse_hashmap(child, remaining - 1, read_value)? {
+            let mut key = label.clone();
+            key.push(selector);
+            key.extend(suffix);
+            result.push((key, value));
+        }
+    }
+    Ok(result)
+}
+
+fn drop_selector_bit<V>(entries: Vec<(DictKey, V)>) -> Vec<(DictKey, V)> {
+    entries
+        .into_iter()
+        .map(|(key, value)| (key[1..].to_vec(), value))
+        .collect()
+}
+
+fn common_prefix<'a>(mut keys: impl Iterator<Item = &'a [bool]>) -> Vec<bool> {
+    let first = match keys.next() {
+        Some(key) => key,
+        None => return Vec::new(),
+    };
+    let mut len = first.len();
+    for key in keys {
+        len = len.min(key.len());
+        for i in 0..len {
+            if key[i] != first[i] {
+                len = i;
+                break;
+            }
+        }
+    }
+    first[..len].to_vec()
+}
+
+/// Number of bits needed to store `n:(#<= max_n)`, i.e. `ceil(log2(max_n + 1))`.
+fn label_len_bits(max_n: usize) -> u32 {
+    if max_n == 0 {
+        0
+    } else {
+        32 - (max_n as u32).leading_zeros()
+    }
+}
+
+pub(crate) fn write_label(
+    builder: &mut CellBuilder,
+    label: &[bool],
+    max_n: usize,
+) -> Result<(), TonCellError> {
+    let n = label.len();
+    let len_bits = label_len_bits(max_n);
+    let long_len = 2 + len_bits as usize + n;
+    let short_len = 2 * n + 2;
+    let same_bit = same_bit(label);
+    let same_len = if same_bit.is_some() {
+        2 + len_bits as usize
+    } else {
+        usize::MAX
+    };
+
+    if same_len <= long_len && same_len <= short_len {
+        // hml_same$11 v:Bit n:(#<= max_n) = HmLabel ~n max_n;
+        builder.store_bit(true)?;
+        builder.store_bit(true)?;
+        builder.store_bit(same_bit.expect("same_len is only minimal when same_bit is set"))?;
+        builder.store_u32(len_bits, n as u32)?;
+    } else if long_len <= short_len {
+        // hml_long$10 n:(#<= max_n) s:(n * Bit) = HmLabel ~n max_n;
+        builder.store_bit(true)?;
+        builder.store_bit(false)?;
+        builder.store_u32(len_bits, n as u32)?;
+        for &bit in label {
+            builder.store_bit(bit)?;
+        }
+    } else {
+        // hml_short$0 len:(Unary ~n) s:(n * Bit) = HmLabel ~n max_n;
+        builder.store_bit(false)?;
+        for _ in 0..n {
+            builder.store_bit(true)?;
+        }
+        builder.store_bit(false)?;
+        for &bit in label {
+            builder.store_bit(bit)?;
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn read_label(parser: &mut CellParser, max_n: usize) -> Result<DictKey, TonCellError> {
+    if !parser.load_bit()? {
+        // hml_short$0
+        let mut n = 0usize;
+        while parser.load_bit()? {
+            n += 1;
+        }
+        (0..n).map(|_| parser.load_bit()).collect()
+    } else if !parser.load_bit()? {
+        // hml_long$10
+        let n = parser.load_u32(label_len_bits(max_n))? as usize;
+        (0..n).map(|_| parser.load_bit()).collect()
+    } else {
+        // hml_same$11
+        let v = parser.load_bit()?;
+        let n = parser.load_u32(label_len_bits(max_n))? as usize;
+        Ok(vec![v; n])
+    }
+}
+
+fn same_bit(label: &[bool]) -> Option<bool> {
+    let first = *label.first()?;
+    label.iter().all(|&bit| bit == first).then_some(first)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY_LEN: usize = 8;
+
+    fn key(value: u8) -> DictKey {
+        (0..KEY_LEN).rev().map(|i| (value >> i) & 1 == 1).collect()
+    }
+
+    fn write_u32(value: &u32, builder: &mut CellBuilder) -> Result<(), TonCellError> {
+        builder.store_u32(32, *value)?;
+        Ok(())
+    }
+
+    fn read_u32(parser: &mut CellParser) -> Result<u32, TonCellError> {
+        parser.load_u32(32)
+    }
+
+    #[test]
+    fn test_build_and_parse_hashmap_round_trip() -> Result<(), TonCellError> {
+        let entries: Vec<(DictKey, u32)> = vec![
+            (key(1), 10),
+            (key(2), 20),
+            (key(200), 30),
+            (key(255), 40),
+        ];
+
+        let root = build_hashmap(entries.clone(), KEY_LEN, &write_u32)?;
+        let mut parsed = parse_hashmap(&root, KEY_LEN, &read_u32)?;
+        parsed.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut expected = entries;
+        expected.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        assert_eq!(expected, parsed);
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_hashmap_rejects_duplicate_keys() {
+        let entries: Vec<(DictKey, u32)> = vec![(key(1), 10), (key(2), 20), (key(1), 99)];
+        let result = build_hashmap(entries, KEY_LEN, &write_u32);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_build_hashmap_rejects_empty_entries() {
+        let entries: Vec<(DictKey, u32)> = Vec::new();
+        let result = build_hashmap(entries, KEY_LEN, &write_u32);
+        assert!(result.is_err());
+    }
+}
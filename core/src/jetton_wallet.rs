@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use num_bigint::BigUint;
+
+use crate::cell::{ArcCell, CellBuilder, TonCellError};
+use crate::state_init::StateInit;
+use crate::TonAddress;
+
+/// Helpers around a jetton wallet's on-chain representation that don't require a
+/// network call.
+pub struct JettonWallet;
+
+impl JettonWallet {
+    /// Computes the address a jetton wallet for `owner` under `jetton_master` will
+    /// have once deployed with `wallet_code`, in workchain 0.
+    pub fn derive_address(
+        owner: &TonAddress,
+        jetton_master: &TonAddress,
+        wallet_code: &ArcCell,
+    ) -> Result<TonAddress, TonCellError> {
+        Self::derive_address_in_workchain(owner, jetton_master, wallet_code, 0)
+    }
+
+    /// Like [`Self::derive_address`], but for a caller-chosen `workchain`.
+    pub fn derive_address_in_workchain(
+        owner: &TonAddress,
+        jetton_master: &TonAddress,
+        wallet_code: &ArcCell,
+        workchain: i32,
+    ) -> Result<TonAddress, TonCellError> {
+        let mut data_builder = CellBuilder::new();
+        data_builder.store_coins(&BigUint::from(0u8))?; // balance:Coins = 0
+        data_builder.store_address(owner)?;
+        data_builder.store_address(jetton_master)?;
+        data_builder.store_reference(wallet_code)?;
+        let data = Arc::new(data_builder.build()?);
+
+        let state_init = StateInit::new(wallet_code.clone(), data);
+        let state_init_hash = state_init.build()?.cell_hash();
+
+        Ok(TonAddress::new(workchain, state_init_hash))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cell::Cell;
+
+    fn address(workchain: i32, hash_byte_start: u8) -> TonAddress {
+        let mut hash_part = [0u8; 32];
+        for (i, byte) in hash_part.iter_mut().enumerate() {
+            *byte = hash_byte_start.wrapping_add(i as u8);
+        }
+        TonAddress {
+            workchain,
+            hash_part,
+        }
+    }
+
+    /// `owner`/`jetton_master`/`wallet_code` below are a fixed, fully specified
+    /// triple; `expected_address` is the `StateInit` hash this crate's own
+    /// `CellBuilder`/`Cell::cell_hash` encoding must produce for them, worked out
+    /// independently from the cell-hashing algorithm the TON whitepaper specifies
+    /// (`sha256(d1 || d2 || data || child depths || child hashes)`). Any
+    /// divergence means `derive_address` no longer matches the on-chain address a
+    /// real wallet of this code/owner/master would be deployed at.
+    #[test]
+    fn test_derive_address_matches_known_state_init_hash() -> Result<(), TonCellError> {
+        let owner = address(0, 0x01);
+        let jetton_master = address(0, 0x21);
+        let wallet_code = Arc::new(Cell::new(vec![0xDE, 0xAD, 0xBE, 0xEF], 32, vec![], false)?);
+
+        let derived = JettonWallet::derive_address(&owner, &jetton_master, &wallet_code)?;
+
+        let expected_hash_part =
+            hex::decode("68150a0fbcda6586f1882457a7aa25334634cb4423547ed7ae16aec643bfdd12")
+                .unwrap();
+        assert_eq!(derived.workchain, 0);
+        assert_eq!(derived.hash_part.to_vec(), expected_hash_part);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_address_is_sensitive_to_every_input() -> Result<(), TonCellError> {
+        let owner = address(0, 0x01);
+        let jetton_master = address(0, 0x21);
+        let wallet_code = Arc::new(Cell::new(vec![0xDE, 0xAD, 0xBE, 0xEF], 32, vec![], false)?);
+
+        let base = JettonWallet::derive_address(&owner, &jetton_master, &wallet_code)?;
+
+        let other_owner = address(0, 0x41);
+        let with_other_owner =
+            JettonWallet::derive_address(&other_owner, &jetton_master, &wallet_code)?;
+        assert_ne!(base, with_other_owner);
+
+        let other_master = address(0, 0x61);
+        let with_other_master =
+            JettonWallet::derive_address(&owner, &other_master, &wallet_code)?;
+        assert_ne!(base, with_other_master);
+
+        let other_code = Arc::new(Cell::new(vec![0xCA, 0xFE, 0xBA, 0xBE], 32, vec![], false)?);
+        let with_other_code = JettonWallet::derive_address(&owner, &jetton_master, &other_code)?;
+        assert_ne!(base, with_other_code);
+
+        let in_workchain = JettonWallet::derive_address_in_workchain(
+            &owner,
+            &jetton_master,
+            &wallet_code,
+            -1,
+        )?;
+        assert_eq!(in_workchain.workchain, -1);
+        assert_eq!(in_workchain.hash_part, base.hash_part);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,471 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use sha2::{Digest, Sha256};
+
+use crate::cell::dict::{self, DictKey};
+use crate::cell::{ArcCell, Cell, CellBuilder, CellParser, TonCellError};
+
+const TAG_OFF_CHAIN: u32 = 0x01;
+const TAG_ON_CHAIN: u32 = 0x00;
+const TAG_CONTENT_SNAKE: u8 = 0x00;
+const TAG_CONTENT_CHUNKS: u8 = 0x01;
+const ATTRIBUTE_KEY_BITS: usize = 256;
+/// Max bytes a single cell in a snake-data chain can hold: 1023 data bits, byte-aligned.
+const SNAKE_CELL_MAX_BYTES: usize = 1023 / 8;
+
+/// TEP-64 on-chain content for a jetton master, with the attributes every jetton
+/// wallet/explorer is expected to understand split out as typed fields. Anything
+/// else found in the dictionary is kept verbatim in `other`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct JettonMetadata {
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub description: Option<String>,
+    pub image: Option<String>,
+    pub decimals: Option<u8>,
+    pub other: BTreeMap<String, Vec<u8>>,
+}
+
+impl JettonMetadata {
+    fn to_attributes(&self) -> BTreeMap<String, Vec<u8>> {
+        let mut attributes = self.other.clone();
+        insert_string(&mut attributes, "name", &self.name);
+        insert_string(&mut attributes, "symbol", &self.symbol);
+        insert_string(&mut attributes, "description", &self.description);
+        insert_string(&mut attributes, "image", &self.image);
+        // decimals is stored as an ASCII string, not a binary integer.
+        insert_string(&mut attributes, "decimals", &self.decimals.map(|d| d.to_string()));
+        attributes
+    }
+
+    fn from_attributes(mut attributes: BTreeMap<String, Vec<u8>>) -> Result<Self, TonCellError> {
+        Ok(JettonMetadata {
+            name: take_string(&mut attributes, "name")?,
+            symbol: take_string(&mut attributes, "symbol")?,
+            description: take_string(&mut attributes, "description")?,
+            image: take_string(&mut attributes, "image")?,
+            decimals: take_string(&mut attributes, "decimals")?
+                .map(|s| s.parse::<u8>())
+                .transpose()
+                .map_err(|e| TonCellError::InternalError(format!("invalid decimals: {e}")))?,
+            other: attributes,
+        })
+    }
+}
+
+/// TEP-64 on-chain content for an NFT item/collection.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NftMetadata {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub image: Option<String>,
+    pub other: BTreeMap<String, Vec<u8>>,
+}
+
+impl NftMetadata {
+    fn to_attributes(&self) -> BTreeMap<String, Vec<u8>> {
+        let mut attributes = self.other.clone();
+        insert_string(&mut attributes, "name", &self.name);
+        insert_string(&mut attributes, "description", &self.description);
+        insert_string(&mut attributes, "image", &self.image);
+        attributes
+    }
+
+    fn from_attributes(mut attributes: BTreeMap<String, Vec<u8>>) -> Result<Self, TonCellError> {
+        Ok(NftMetadata {
+            name: take_string(&mut attributes, "name")?,
+            description: take_string(&mut attributes, "description")?,
+            image: take_string(&mut attributes, "image")?,
+            other: attributes,
+        })
+    }
+}
+
+fn insert_string(attributes: &mut BTreeMap<String, Vec<u8>>, key: &str, value: &Option<String>) {
+    if let Some(value) = value {
+        attributes.insert(key.to_string(), value.clone().into_bytes());
+    }
+}
+
+fn take_string(
+    attributes: &mut BTreeMap<String, Vec<u8>>,
+    key: &str,
+) -> Result<Option<String>, TonCellError> {
+    attributes
+        .remove(key)
+        .map(|bytes| {
+            String::from_utf8(bytes)
+                .map_err(|e| TonCellError::InternalError(format!("invalid utf8 in {key}: {e}")))
+        })
+        .transpose()
+}
+
+/// TEP-64 content, either a pointer to off-chain metadata or the metadata itself.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JettonContent {
+    /// `off-chain` content: `tag#01 uri:(SnakeData ~n) = FullContent;`
+    OffChain { uri: String },
+    /// `on-chain` content: `tag#00 data:(HashmapE 256 ^ContentData) = FullContent;`
+    OnChain(JettonMetadata),
+}
+
+impl JettonContent {
+    pub fn build(&self) -> Result<Cell, TonCellError> {
+        match self {
+            JettonContent::OffChain { uri } => build_off_chain(uri),
+            JettonContent::OnChain(metadata) => build_on_chain(&metadata.to_attributes()),
+        }
+    }
+
+    pub fn parse(cell: &Cell) -> Result<Self, TonCellError> {
+        match parse_content(cell)? {
+            ParsedContent::OffChain(uri) => Ok(JettonContent::OffChain { uri }),
+            ParsedContent::OnChain(attributes) => {
+                Ok(JettonContent::OnChain(JettonMetadata::from_attributes(attributes)?))
+            }
+        }
+    }
+}
+
+/// TEP-64 content for an NFT item/collection; same wire format as [`JettonContent`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum NftContent {
+    OffChain { uri: String },
+    OnChain(NftMetadata),
+}
+
+impl NftContent {
+    pub fn build(&self) -> Result<Cell, TonCellError> {
+        match self {
+            NftContent::OffChain { uri } => build_off_chain(uri),
+            NftContent::OnChain(metadata) => build_on_chain(&metadata.to_attributes()),
+        }
+    }
+
+    pub fn parse(cell: &Cell) -> Result<Self, TonCellError> {
+        match parse_content(cell)? {
+            ParsedContent::OffChain(uri) => Ok(NftContent::OffChain { uri }),
+            ParsedContent::OnChain(attributes) => {
+                Ok(NftContent::OnChain(NftMetadata::from_attributes(attributes)?))
+            }
+        }
+    }
+}
+
+enum ParsedContent {
+    OffChain(String),
+    OnChain(BTreeMap<String, Vec<u8>>),
+}
+
+fn build_off_chain(uri: &str) -> Result<Cell, TonCellError> {
+    let mut data = vec![TAG_OFF_CHAIN as u8];
+    data.extend_from_slice(uri.as_bytes());
+    Ok((*build_snake_chain(&data)?).clone())
+}
+
+fn build_on_chain(attributes: &BTreeMap<String, Vec<u8>>) -> Result<Cell, TonCellError> {
+    let mut builder = CellBuilder::new();
+    builder.store_u32(8, TAG_ON_CHAIN)?;
+    if attributes.is_empty() {
+        builder.store_bit(false)?;
+        return Ok(builder.build()?);
+    }
+
+    let entries: Vec<(DictKey, Vec<u8>)> = attributes
+        .iter()
+        .map(|(name, value)| (attribute_key(name), value.clone()))
+        .collect();
+    let root = dict::build_hashmap(entries, ATTRIBUTE_KEY_BITS, &|value: &Vec<u8>, b| {
+        let mut snake = vec![TAG_CONTENT_SNAKE];
+        snake.extend_from_slice(value);
+        b.store_reference(&build_snake_chain(&snake)?)?;
+        Ok(())
+    })?;
+    builder.store_bit(true)?;
+    builder.store_reference(&root)?;
+    Ok(builder.build()?)
+}
+
+fn parse_content(cell: &Cell) -> Result<ParsedContent, TonCellError> {
+    let mut parser = cell.parser();
+    match parser.load_u32(8)? as u8 {
+        tag if tag as u32 == TAG_OFF_CHAIN => {
+            let bytes = read_snake_tail(&mut parser, cell)?;
+            let uri = String::from_utf8(bytes)
+                .map_err(|e| TonCellError::InternalError(format!("invalid utf8 uri: {e}")))?;
+            Ok(ParsedContent::OffChain(uri))
+        }
+        tag if tag as u32 == TAG_ON_CHAIN => {
+            if !parser.load_bit()? {
+                return Ok(ParsedContent::OnChain(BTreeMap::new()));
+            }
+            let root = parser.next_reference()?;
+            let entries = dict::parse_hashmap(&root, ATTRIBUTE_KEY_BITS, &|p| {
+                let value_cell = p.next_reference()?;
+                read_content_value(&value_cell)
+            })?;
+            let attributes = entries
+                .into_iter()
+                .map(|(key, value)| (attribute_name(&key), value))
+                .collect();
+            Ok(ParsedContent::OnChain(attributes))
+        }
+        other => Err(TonCellError::InternalError(format!(
+            "unsupported TEP-64 content tag: {other:#04x}"
+        ))),
+    }
+}
+
+fn read_content_value(cell: &Cell) -> Result<Vec<u8>, TonCellError> {
+    let mut parser = cell.parser();
+    match parser.load_u32(8)? as u8 {
+        TAG_CONTENT_SNAKE => read_snake_tail(&mut parser, cell),
+        TAG_CONTENT_CHUNKS => read_chunks(&mut parser),
+        other => Err(TonCellError::InternalError(format!(
+            "unsupported TEP-64 content value tag: {other:#04x}"
+        ))),
+    }
+}
+
+/// Packs `data` (tag byte included, by convention, as `data[0]`) into a chain of
+/// cells, splitting exactly at cell capacity; any bytes beyond
+/// [`SNAKE_CELL_MAX_BYTES`] overflow into a single child ref.
+fn build_snake_chain(data: &[u8]) -> Result<ArcCell, TonCellError> {
+    let take = data.len().min(SNAKE_CELL_MAX_BYTES);
+    let mut builder = CellBuilder::new();
+    for &byte in &data[..take] {
+        builder.store_u32(8, byte as u32)?;
+    }
+    if take < data.len() {
+        builder.store_reference(&build_snake_chain(&data[take..])?)?;
+    }
+    Ok(Arc::new(builder.build()?))
+}
+
+/// Reads a snake-data chain whose first cell's tag byte was already consumed by
+/// `parser`.
+fn read_snake_tail(parser: &mut CellParser, cell: &Cell) -> Result<Vec<u8>, TonCellError> {
+    let mut data = read_snake_body(parser, cell.bit_len() / 8 - 1)?;
+    if let Ok(next) = cell.reference(0) {
+        data.extend(read_snake_cell(next)?);
+    }
+    Ok(data)
+}
+
+/// Reads a continuation cell of a snake-data chain: pure bytes, no tag.
+fn read_snake_cell(cell: &Cell) -> Result<Vec<u8>, TonCellError> {
+    let mut parser = cell.parser();
+    let mut data = read_snake_body(&mut parser, cell.bit_len() / 8)?;
+    if let Ok(next) = cell.reference(0) {
+        data.extend(read_snake_cell(next)?);
+    }
+    Ok(data)
+}
+
+fn read_snake_body(parser: &mut CellParser, byte_len: usize) -> Result<Vec<u8>, TonCellError> {
+    (0..byte_len).map(|_| Ok(parser.load_u32(8)? as u8)).collect()
+}
+
+/// Reads the legacy `chunks#01 data:(HashmapE 32 ^Cell)` value representation:
+/// fixed-index chunks concatenated in index order. Only reading is supported;
+/// [`build_on_chain`] always emits snake-encoded values.
+fn read_chunks(parser: &mut CellParser) -> Result<Vec<u8>, TonCellError> {
+    if !parser.load_bit()? {
+        return Ok(Vec::new());
+    }
+    let root = parser.next_reference()?;
+    let mut chunks = dict::parse_hashmap(&root, 32, &|p| p.next_reference())?;
+    chunks.sort_by_key(|(key, _)| bits_to_index(key));
+
+    let mut data = Vec::new();
+    for (_, chunk) in chunks {
+        let mut chunk_parser = chunk.parser();
+        data.extend(read_snake_body(&mut chunk_parser, chunk.bit_len() / 8)?);
+    }
+    Ok(data)
+}
+
+fn bits_to_index(bits: &[bool]) -> u32 {
+    bits.iter().fold(0u32, |acc, &bit| (acc << 1) | bit as u32)
+}
+
+fn attribute_key(name: &str) -> DictKey {
+    let hash = Sha256::digest(name.as_bytes());
+    hash.iter()
+        .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+        .collect()
+}
+
+/// TEP-64 dictionary keys are attribute-name hashes, not the names themselves, so
+/// a parsed dictionary can only be matched back against the well-known attribute
+/// names it was built from; anything else surfaces under its hash, hex-encoded.
+fn attribute_name(key: &DictKey) -> String {
+    const KNOWN: &[&str] = &[
+        "name",
+        "symbol",
+        "description",
+        "image",
+        "decimals",
+        "image_data",
+        "uri",
+        "content_url",
+    ];
+    for candidate in KNOWN {
+        if attribute_key(candidate) == *key {
+            return candidate.to_string();
+        }
+    }
+    let bytes: Vec<u8> = key
+        .chunks(8)
+        .map(|chunk| chunk.iter().fold(0u8, |acc, &bit| (acc << 1) | bit as u8))
+        .collect();
+    hex::encode(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Longer than [`SNAKE_CELL_MAX_BYTES`], so encoding it forces at least two
+    /// cells in the snake-data chain.
+    fn long_string() -> String {
+        "a".repeat(SNAKE_CELL_MAX_BYTES * 2 + 10)
+    }
+
+    #[test]
+    fn test_jetton_content_off_chain_round_trip() -> Result<(), TonCellError> {
+        let content = JettonContent::OffChain {
+            uri: "https://example.com/jetton.json".to_string(),
+        };
+        let cell = content.build()?;
+        let parsed = JettonContent::parse(&cell)?;
+        assert_eq!(parsed, content);
+        Ok(())
+    }
+
+    #[test]
+    fn test_jetton_content_off_chain_round_trip_spans_multiple_cells() -> Result<(), TonCellError> {
+        let content = JettonContent::OffChain { uri: long_string() };
+        let cell = content.build()?;
+        // the tag byte plus the full URI no longer fit in one cell.
+        assert!(cell.reference(0).is_ok());
+        let parsed = JettonContent::parse(&cell)?;
+        assert_eq!(parsed, content);
+        Ok(())
+    }
+
+    #[test]
+    fn test_jetton_content_on_chain_round_trip() -> Result<(), TonCellError> {
+        let metadata = JettonMetadata {
+            name: Some("Example".to_string()),
+            symbol: Some("EX".to_string()),
+            description: Some(long_string()),
+            image: Some("https://example.com/image.png".to_string()),
+            decimals: Some(9),
+            other: BTreeMap::new(),
+        };
+        let content = JettonContent::OnChain(metadata.clone());
+        let cell = content.build()?;
+
+        let parsed = JettonContent::parse(&cell)?;
+        match parsed {
+            JettonContent::OnChain(parsed_metadata) => assert_eq!(parsed_metadata, metadata),
+            JettonContent::OffChain { .. } => panic!("expected on-chain content"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_jetton_content_on_chain_empty_attributes_round_trip() -> Result<(), TonCellError> {
+        let content = JettonContent::OnChain(JettonMetadata::default());
+        let cell = content.build()?;
+        let parsed = JettonContent::parse(&cell)?;
+        assert_eq!(parsed, content);
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimals_are_stored_as_an_ascii_string() -> Result<(), TonCellError> {
+        let metadata = JettonMetadata {
+            decimals: Some(9),
+            ..Default::default()
+        };
+        assert_eq!(
+            metadata.to_attributes().get("decimals"),
+            Some(&b"9".to_vec())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_nft_metadata_round_trip() -> Result<(), TonCellError> {
+        let metadata = NftMetadata {
+            name: Some("NFT #1".to_string()),
+            description: Some(long_string()),
+            image: Some("https://example.com/nft.png".to_string()),
+            other: BTreeMap::new(),
+        };
+        let content = NftContent::OnChain(metadata.clone());
+        let cell = content.build()?;
+
+        let parsed = NftContent::parse(&cell)?;
+        match parsed {
+            NftContent::OnChain(parsed_metadata) => assert_eq!(parsed_metadata, metadata),
+            NftContent::OffChain { .. } => panic!("expected on-chain content"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown_attribute_survives_round_trip_under_its_hashed_key() -> Result<(), TonCellError> {
+        let mut other = BTreeMap::new();
+        other.insert("custom_trait".to_string(), b"rare".to_vec());
+        let metadata = JettonMetadata {
+            other,
+            ..Default::default()
+        };
+        let cell = JettonContent::OnChain(metadata).build()?;
+
+        match JettonContent::parse(&cell)? {
+            JettonContent::OnChain(parsed) => {
+                // the dictionary is keyed by attribute-name hash, not the name itself, so
+                // an attribute outside `KNOWN` in `attribute_name` can only be recovered
+                // under its hex-encoded hash (see the doc comment on `attribute_name`).
+                let hashed_key = hex::encode(Sha256::digest(b"custom_trait"));
+                assert_eq!(parsed.other.get(&hashed_key), Some(&b"rare".to_vec()));
+            }
+            JettonContent::OffChain { .. } => panic!("expected on-chain content"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_chunks_legacy_format() -> Result<(), TonCellError> {
+        // chunks#01 data:(HashmapE 32 ^Cell) = ContentData;
+        let chunk_0 = Arc::new(Cell::new(b"hello ".to_vec(), 6 * 8, vec![], false)?);
+        let chunk_1 = Arc::new(Cell::new(b"world".to_vec(), 5 * 8, vec![], false)?);
+        let entries: Vec<(DictKey, ArcCell)> = vec![
+            (index_key(0), chunk_0),
+            (index_key(1), chunk_1),
+        ];
+        let root = dict::build_hashmap(entries, 32, &|chunk: &ArcCell, b| {
+            b.store_reference(chunk)?;
+            Ok(())
+        })?;
+
+        let mut builder = CellBuilder::new();
+        builder.store_u32(8, TAG_CONTENT_CHUNKS as u32)?;
+        builder.store_bit(true)?;
+        builder.store_reference(&root)?;
+        let cell = builder.build()?;
+
+        let value = read_content_value(&cell)?;
+        assert_eq!(value, b"hello world".to_vec());
+        Ok(())
+    }
+
+    fn index_key(index: u32) -> DictKey {
+        (0..32).rev().map(|i| (index >> i) & 1 == 1).collect()
+    }
+}
@@ -0,0 +1,280 @@
+use std::sync::Arc;
+
+use num_bigint::BigUint;
+
+use crate::cell::dict::{self, DictKey};
+use crate::cell::{ArcCell, Cell, CellBuilder, CellParser, TonCellError};
+use crate::TonAddress;
+
+/// Number of bits in the dictionary key: a full `addr_std` `MsgAddress`
+/// (2-bit tag + `anycast:(Maybe Anycast)` + 8-bit workchain + 256-bit hash).
+const AIRDROP_KEY_BITS: usize = 267;
+
+/// One entry of a mintless-jetton airdrop dictionary:
+///
+/// ```raw
+/// _ amount:(VarUInteger 16) start_from:uint48 expire_at:uint48 = AirdropItem;
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct AirdropItem {
+    pub amount: BigUint,
+    pub start_from: u64,
+    pub expire_at: u64,
+}
+
+impl AirdropItem {
+    fn write_to(&self, builder: &mut CellBuilder) -> Result<(), TonCellError> {
+        builder.store_coins(&self.amount)?;
+        builder.store_u64(48, self.start_from)?;
+        builder.store_u64(48, self.expire_at)?;
+        Ok(())
+    }
+
+    fn read(parser: &mut CellParser) -> Result<Self, TonCellError> {
+        let amount = parser.load_coins()?;
+        let start_from = parser.load_u64(48)?;
+        let expire_at = parser.load_u64(48)?;
+        Ok(AirdropItem {
+            amount,
+            start_from,
+            expire_at,
+        })
+    }
+}
+
+/// A single `(owner, item)` pair of the airdrop dictionary, keyed by the owner's
+/// full `MsgAddress`.
+#[derive(Clone, Debug)]
+pub struct AirdropEntry {
+    pub owner: TonAddress,
+    pub item: AirdropItem,
+}
+
+/// The claim proof a not-yet-deployed mintless jetton wallet expects in the
+/// `custom_payload` of an ordinary jetton transfer/burn: a Merkle proof that the
+/// claimer is present in the airdrop dictionary baked into the wallet's code/state,
+/// plus the `AirdropItem` the proof resolves to.
+///
+/// This type stops at the `custom_payload` cell: it does not build a
+/// `forward_payload` and does not wire up the wallet's `StateInit`. The
+/// `custom_payload` cell can be passed to
+/// [`crate::message::jetton::burn::JettonBurnMessage::with_custom_payload`];
+/// attaching a [`crate::state_init::StateInit`] to the outer internal message is
+/// left to the caller.
+#[derive(Clone, Debug)]
+pub struct MintlessClaim {
+    pub custom_payload: ArcCell,
+    pub item: AirdropItem,
+}
+
+impl MintlessClaim {
+    /// Builds the claim proof out of the full set of airdrop dictionary `entries`.
+    /// The dictionary is assembled canonically, so its root hashes identically to
+    /// the on-chain `HashmapE 267 AirdropItem` it is derived from.
+    pub fn from_entries(
+        entries: Vec<AirdropEntry>,
+        claimer: &TonAddress,
+    ) -> Result<Self, TonCellError> {
+        let keyed: Vec<(DictKey, AirdropItem)> = entries
+            .into_iter()
+            .map(|entry| (address_key(&entry.owner), entry.item))
+            .collect();
+        let root = dict::build_hashmap(keyed, AIRDROP_KEY_BITS, &|item, builder| {
+            item.write_to(builder)
+        })?;
+        Self::from_dict_root(&root, claimer)
+    }
+
+    /// Builds the claim proof directly out of the on-chain dictionary `root` cell,
+    /// e.g. one read out of the jetton wallet's code/state.
+    pub fn from_dict_root(root: &Cell, claimer: &TonAddress) -> Result<Self, TonCellError> {
+        let key = address_key(claimer);
+        let (pruned_root, item) = prune(root, AIRDROP_KEY_BITS, &key)?;
+
+        // merkle_proof#03 {X:Type} virtual_hash:bits256 depth:uint16 virtual_root:^X
+        //                 = MERKLE_PROOF X;
+        let mut data = Vec::with_capacity(35);
+        data.push(3u8);
+        data.extend_from_slice(&root.cell_hash());
+        data.extend_from_slice(&root.depth().to_be_bytes());
+        let proof = Cell::new(data, 35 * 8, vec![pruned_root], true)?;
+
+        Ok(MintlessClaim {
+            custom_payload: Arc::new(proof),
+            item,
+        })
+    }
+}
+
+/// Encodes a `MsgAddress` as the 267-bit big-endian key used by the airdrop
+/// dictionary (standard, non-anycast addresses only, matching what the airdrop
+/// generator produces).
+fn address_key(address: &TonAddress) -> DictKey {
+    let mut bits = Vec::with_capacity(AIRDROP_KEY_BITS);
+    bits.push(true); // addr_std$10, bit 1
+    bits.push(false); // addr_std$10, bit 2
+    bits.push(false); // anycast:(Maybe Anycast) = nothing
+    let workchain = address.workchain as i8;
+    for i in (0..8).rev() {
+        bits.push((workchain >> i) & 1 == 1);
+    }
+    for byte in address.hash_part {
+        for i in (0..8).rev() {
+            bits.push((byte >> i) & 1 == 1);
+        }
+    }
+    bits
+}
+
+/// Walks down the dictionary cell tree along `remaining_key`, keeping every cell
+/// on the path intact and replacing every sibling subtree with a pruned branch
+/// cell. Returns the rebuilt (partially pruned) subtree root together with the
+/// `AirdropItem` found at the end of the path.
+fn prune(
+    cell: &Cell,
+    key_len: usize,
+    remaining_key: &[bool],
+) -> Result<(ArcCell, AirdropItem), TonCellError> {
+    let mut parser = cell.parser();
+    let label = dict::read_label(&mut parser, key_len)?;
+    if remaining_key.len() < label.len() || remaining_key[..label.len()] != label[..] {
+        return Err(TonCellError::InternalError(
+            "claimer address is not present in the airdrop dictionary".to_string(),
+        ));
+    }
+
+    let remaining = key_len - label.len();
+    if remaining == 0 {
+        let item = AirdropItem::read(&mut parser)?;
+        return Ok((Arc::new(cell.clone()), item));
+    }
+
+    let selector = remaining_key[label.len()];
+    let rest = &remaining_key[label.len() + 1..];
+    let on_path_idx = selector as usize;
+    let off_path_idx = 1 - on_path_idx;
+
+    let (pruned_on_path, item) = prune(cell.reference(on_path_idx)?, remaining - 1, rest)?;
+    let pruned_off_path = prune_branch(cell.reference(off_path_idx)?)?;
+
+    let mut builder = CellBuilder::new();
+    dict::write_label(&mut builder, &label, key_len)?;
+    let refs = if selector {
+        [pruned_off_path, pruned_on_path.clone()]
+    } else {
+        [pruned_on_path.clone(), pruned_off_path]
+    };
+    builder.store_reference(&refs[0])?;
+    builder.store_reference(&refs[1])?;
+
+    Ok((Arc::new(builder.build()?), item))
+}
+
+/// Replaces an off-path subtree with a pruned branch cell that carries only its
+/// hash and depth, per:
+///
+/// ```raw
+/// prunned_branch#01 level:(## 8) { level = 1 } hash:bits256 depth:uint16
+///                    = PrunnedBranch;
+/// ```
+fn prune_branch(cell: &Cell) -> Result<ArcCell, TonCellError> {
+    let mut data = Vec::with_capacity(36);
+    data.push(1u8);
+    data.push(1u8); // level mask: the pruned subtree was an ordinary (level 0) cell
+    data.extend_from_slice(&cell.cell_hash());
+    data.extend_from_slice(&cell.depth().to_be_bytes());
+    Ok(Arc::new(Cell::new(data, 36 * 8, vec![], true)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn owner(hash_byte: u8) -> TonAddress {
+        TonAddress {
+            workchain: 0,
+            hash_part: [hash_byte; 32],
+        }
+    }
+
+    fn item(amount: u64) -> AirdropItem {
+        AirdropItem {
+            amount: BigUint::from(amount),
+            start_from: 0,
+            expire_at: 4_102_444_800,
+        }
+    }
+
+    fn sample_entries() -> Vec<AirdropEntry> {
+        (0u8..8)
+            .map(|i| AirdropEntry {
+                owner: owner(i),
+                item: item(1_000_000 * (i as u64 + 1)),
+            })
+            .collect()
+    }
+
+    /// Counts real (unpruned) leaves reachable from `cell`: a pruned branch cell
+    /// contributes 0, a leaf (no children) contributes 1, a fork recurses.
+    fn count_leaves(cell: &Cell) -> usize {
+        if cell.is_exotic() {
+            return 0;
+        }
+        match (cell.reference(0), cell.reference(1)) {
+            (Ok(left), Ok(right)) => count_leaves(left) + count_leaves(right),
+            _ => 1,
+        }
+    }
+
+    /// Reads back the `virtual_hash` a `merkle_proof#03` cell declares.
+    fn declared_hash(proof: &Cell) -> Result<[u8; 32], TonCellError> {
+        let mut parser = proof.parser();
+        parser.load_u32(8)?; // tag
+        let mut hash = [0u8; 32];
+        for byte in hash.iter_mut() {
+            *byte = parser.load_u32(8)? as u8;
+        }
+        Ok(hash)
+    }
+
+    #[test]
+    fn test_claim_proof_has_single_leaf_and_matching_root_hash() -> Result<(), TonCellError> {
+        let entries = sample_entries();
+        let claimer = entries[3].owner.clone();
+        let expected_item = entries[3].item.clone();
+
+        let keyed: Vec<(DictKey, AirdropItem)> = entries
+            .iter()
+            .map(|entry| (address_key(&entry.owner), entry.item.clone()))
+            .collect();
+        let root = dict::build_hashmap(keyed, AIRDROP_KEY_BITS, &|item: &AirdropItem, builder| {
+            item.write_to(builder)
+        })?;
+
+        let claim = MintlessClaim::from_dict_root(&root, &claimer)?;
+        assert_eq!(claim.item, expected_item);
+
+        assert_eq!(declared_hash(&claim.custom_payload)?, root.cell_hash());
+
+        let pruned_root = claim.custom_payload.reference(0)?;
+        assert_eq!(count_leaves(pruned_root), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_claim_proof_rejects_absent_claimer() {
+        let entries = sample_entries();
+        let keyed: Vec<(DictKey, AirdropItem)> = entries
+            .iter()
+            .map(|entry| (address_key(&entry.owner), entry.item.clone()))
+            .collect();
+        let root = dict::build_hashmap(keyed, AIRDROP_KEY_BITS, &|item: &AirdropItem, builder| {
+            item.write_to(builder)
+        })
+        .unwrap();
+
+        let result = MintlessClaim::from_dict_root(&root, &owner(200));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use crate::cell::{ArcCell, Cell, CellBuilder, TonCellError};
+use crate::tlb_types::primitives::out_list::OutAction;
+use crate::tlb_types::traits::TLBObject;
+
+/// An order for a `MultisigWallet` to execute once enough custodians have signed
+/// it:
+///
+/// ```raw
+/// _ query_id:uint64 actions:^(OutList n) = Order;
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct MultisigOrder {
+    /// arbitrary request number.
+    pub query_id: u64,
+    pub actions: Vec<OutAction>,
+}
+
+impl MultisigOrder {
+    pub fn new(actions: Vec<OutAction>) -> Self {
+        MultisigOrder {
+            query_id: 0,
+            actions,
+        }
+    }
+
+    pub fn with_query_id(&mut self, query_id: u64) -> &mut Self {
+        self.query_id = query_id;
+        self
+    }
+
+    /// Builds the order cell. Its representation hash is what every custodian
+    /// signs independently before the order is assembled and broadcast.
+    pub fn build_order(&self) -> Result<Cell, TonCellError> {
+        let mut builder = CellBuilder::new();
+        builder.store_u64(64, self.query_id)?;
+        let mut actions_builder = CellBuilder::new();
+        self.actions.write_to(&mut actions_builder)?;
+        let actions_cell: ArcCell = Arc::new(actions_builder.build()?);
+        builder.store_reference(&actions_cell)?;
+        Ok(builder.build()?)
+    }
+
+    pub fn parse(cell: &Cell) -> Result<Self, TonCellError> {
+        let mut parser = cell.parser();
+        let query_id = parser.load_u64(64)?;
+        let actions_cell = parser.next_reference()?;
+        let actions: Vec<OutAction> = actions_cell.parser().load_tlb()?;
+        parser.ensure_empty()?;
+        Ok(MultisigOrder { query_id, actions })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::send_msg;
+
+    #[test]
+    fn test_build_and_parse_round_trip() -> Result<(), TonCellError> {
+        let mut order = MultisigOrder::new(vec![send_msg(1), send_msg(3)]);
+        order.with_query_id(42);
+
+        let cell = order.build_order()?;
+        let parsed = MultisigOrder::parse(&cell)?;
+
+        assert_eq!(parsed, order);
+        Ok(())
+    }
+
+    #[test]
+    fn test_different_actions_build_different_orders() -> Result<(), TonCellError> {
+        let a = MultisigOrder::new(vec![send_msg(1)]).build_order()?;
+        let b = MultisigOrder::new(vec![send_msg(2)]).build_order()?;
+        assert_ne!(a.cell_hash(), b.cell_hash());
+        Ok(())
+    }
+}
@@ -0,0 +1,210 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+
+use super::order::MultisigOrder;
+use crate::cell::dict::{self, DictKey};
+use crate::cell::{ArcCell, Cell, CellBuilder, TonCellError};
+
+const SIGNER_INDEX_BITS: usize = 8;
+
+/// One custodian's signature over an order, keyed by their index in the
+/// multisig's custodian list.
+#[derive(Clone, Debug)]
+pub struct IndexedSignature {
+    pub signer_index: u8,
+    pub signature: Signature,
+}
+
+/// Signs `order`'s representation hash with one custodian's keypair.
+pub fn sign(order: &MultisigOrder, signer_index: u8, keypair: &SigningKey) -> Result<IndexedSignature, TonCellError> {
+    let hash = order.build_order()?.cell_hash();
+    Ok(IndexedSignature {
+        signer_index,
+        signature: keypair.sign(&hash),
+    })
+}
+
+/// Collects and verifies custodian signatures over a single [`MultisigOrder`]
+/// until there are enough to assemble the outgoing external message.
+#[derive(Clone, Debug)]
+pub struct MultisigSignatures {
+    custodians: Vec<VerifyingKey>,
+    k: usize,
+    collected: BTreeMap<u8, Signature>,
+}
+
+impl MultisigSignatures {
+    /// `custodians` is the multisig's full custodian list, indexed the same way
+    /// the contract expects; `k` is the number of valid signatures required to
+    /// assemble and broadcast an order.
+    pub fn new(custodians: Vec<VerifyingKey>, k: usize) -> Self {
+        MultisigSignatures {
+            custodians,
+            k,
+            collected: BTreeMap::new(),
+        }
+    }
+
+    /// Verifies `signature` against `order`'s representation hash using the
+    /// custodian it claims to be from, then adds it to the collected set.
+    pub fn add(&mut self, order: &MultisigOrder, signature: IndexedSignature) -> Result<(), TonCellError> {
+        let public_key = self.custodians.get(signature.signer_index as usize).ok_or_else(|| {
+            TonCellError::InternalError(format!(
+                "unknown custodian index {}",
+                signature.signer_index
+            ))
+        })?;
+        let hash = order.build_order()?.cell_hash();
+        public_key.verify(&hash, &signature.signature).map_err(|e| {
+            TonCellError::InternalError(format!(
+                "invalid signature from custodian {}: {e}",
+                signature.signer_index
+            ))
+        })?;
+        self.collected.insert(signature.signer_index, signature.signature);
+        Ok(())
+    }
+
+    /// Number of distinct, verified signatures collected so far.
+    pub fn len(&self) -> usize {
+        self.collected.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.collected.is_empty()
+    }
+
+    /// Assembles `order` and its collected signatures into the body the multisig
+    /// contract's external message expects, once at least `k` valid signatures
+    /// have been collected:
+    ///
+    /// ```raw
+    /// _ signatures:(HashmapE 8 Signature) order:^Order = MultisigExternalBody;
+    /// ```
+    pub fn assemble(&self, order: &MultisigOrder) -> Result<Cell, TonCellError> {
+        if self.collected.len() < self.k {
+            return Err(TonCellError::InternalError(format!(
+                "only {} of the required {} signatures have been collected",
+                self.collected.len(),
+                self.k
+            )));
+        }
+
+        let entries: Vec<(DictKey, Signature)> = self
+            .collected
+            .iter()
+            .map(|(&index, signature)| (index_key(index), *signature))
+            .collect();
+        let signatures_root = dict::build_hashmap(entries, SIGNER_INDEX_BITS, &|signature: &Signature, b| {
+            for byte in signature.to_bytes() {
+                b.store_u32(8, byte as u32)?;
+            }
+            Ok(())
+        })?;
+
+        let mut builder = CellBuilder::new();
+        builder.store_bit(true)?; // HashmapE: signatures present
+        builder.store_reference(&signatures_root)?;
+        let order_cell: ArcCell = Arc::new(order.build_order()?);
+        builder.store_reference(&order_cell)?;
+        Ok(builder.build()?)
+    }
+}
+
+fn index_key(index: u8) -> DictKey {
+    (0..8).rev().map(|i| (index >> i) & 1 == 1).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::send_msg;
+
+    fn custodian(seed: u8) -> SigningKey {
+        SigningKey::from_bytes(&[seed; 32])
+    }
+
+    fn sample_order() -> MultisigOrder {
+        MultisigOrder::new(vec![send_msg(1)])
+    }
+
+    #[test]
+    fn test_add_accepts_valid_signature_and_assemble_succeeds_at_threshold() -> Result<(), TonCellError> {
+        let signers = vec![custodian(1), custodian(2), custodian(3)];
+        let custodians = signers.iter().map(|s| s.verifying_key()).collect();
+        let order = sample_order();
+
+        let mut signatures = MultisigSignatures::new(custodians, 2);
+        assert!(signatures.is_empty());
+
+        signatures.add(&order, sign(&order, 0, &signers[0])?)?;
+        assert_eq!(signatures.len(), 1);
+        assert!(signatures.assemble(&order).is_err());
+
+        signatures.add(&order, sign(&order, 1, &signers[1])?)?;
+        assert_eq!(signatures.len(), 2);
+        assert!(signatures.assemble(&order).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_rejects_unknown_custodian_index() -> Result<(), TonCellError> {
+        let signers = vec![custodian(1)];
+        let custodians = signers.iter().map(|s| s.verifying_key()).collect();
+        let order = sample_order();
+
+        let mut signatures = MultisigSignatures::new(custodians, 1);
+        let forged = sign(&order, 5, &signers[0])?;
+        assert!(signatures.add(&order, forged).is_err());
+        assert!(signatures.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_rejects_signature_from_wrong_custodian() -> Result<(), TonCellError> {
+        let signers = vec![custodian(1), custodian(2)];
+        let custodians = signers.iter().map(|s| s.verifying_key()).collect();
+        let order = sample_order();
+
+        // signed by custodian 1's key but claims to be custodian 0.
+        let mismatched = sign(&order, 0, &signers[1])?;
+
+        let mut signatures = MultisigSignatures::new(custodians, 1);
+        assert!(signatures.add(&order, mismatched).is_err());
+        assert!(signatures.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_rejects_signature_over_a_different_order() -> Result<(), TonCellError> {
+        let signers = vec![custodian(1)];
+        let custodians = signers.iter().map(|s| s.verifying_key()).collect();
+        let order = sample_order();
+        let mut other_order = sample_order();
+        other_order.with_query_id(7);
+
+        let signature_over_other_order = sign(&other_order, 0, &signers[0])?;
+
+        let mut signatures = MultisigSignatures::new(custodians, 1);
+        assert!(signatures.add(&order, signature_over_other_order).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_assemble_refuses_below_threshold() -> Result<(), TonCellError> {
+        let signers = vec![custodian(1), custodian(2), custodian(3)];
+        let custodians = signers.iter().map(|s| s.verifying_key()).collect();
+        let order = sample_order();
+
+        let mut signatures = MultisigSignatures::new(custodians, 3);
+        signatures.add(&order, sign(&order, 0, &signers[0])?)?;
+        signatures.add(&order, sign(&order, 1, &signers[1])?)?;
+
+        let result = signatures.assemble(&order);
+        assert!(result.is_err());
+        Ok(())
+    }
+}
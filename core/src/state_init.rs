@@ -0,0 +1,37 @@
+use crate::cell::{ArcCell, Cell, CellBuilder, TonCellError};
+
+/// The `StateInit` a contract is deployed with (and whose hash determines its
+/// address):
+///
+/// ```raw
+/// _ split_depth:(Maybe (## 5)) special:(Maybe TickTock)
+///   code:(Maybe ^Cell) data:(Maybe ^Cell)
+///   library:(HashmapE 256 SimpleLib) = StateInit;
+/// ```
+///
+/// `split_depth`, `special` and `library` are not used by ordinary contracts, so
+/// this type only exposes `code`/`data`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StateInit {
+    pub code: Option<ArcCell>,
+    pub data: Option<ArcCell>,
+}
+
+impl StateInit {
+    pub fn new(code: ArcCell, data: ArcCell) -> Self {
+        StateInit {
+            code: Some(code),
+            data: Some(data),
+        }
+    }
+
+    pub fn build(&self) -> Result<Cell, TonCellError> {
+        let mut builder = CellBuilder::new();
+        builder.store_bit(false)?; // split_depth: nothing
+        builder.store_bit(false)?; // special: nothing
+        builder.store_ref_cell_optional(self.code.as_ref())?;
+        builder.store_ref_cell_optional(self.data.as_ref())?;
+        builder.store_bit(false)?; // library: empty HashmapE
+        Ok(builder.build()?)
+    }
+}
@@ -0,0 +1,17 @@
+//! Fixtures shared by this crate's `#[cfg(test)]` modules, so unrelated test
+//! suites don't each redefine the same throwaway values.
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use crate::cell::Cell;
+use crate::tlb_types::primitives::out_list::OutAction;
+
+/// A minimal `OutAction::SendMsg` for tests that only care about telling one
+/// out-action apart from another, not the contents of its message.
+pub(crate) fn send_msg(mode: u8) -> OutAction {
+    OutAction::SendMsg {
+        mode,
+        message: Arc::new(Cell::new(vec![0xAA], 8, vec![], false).unwrap()),
+    }
+}
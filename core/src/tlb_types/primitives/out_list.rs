@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use crate::cell::{ArcCell, CellBuilder, CellParser, TonCellError};
+use crate::tlb_types::traits::TLBObject;
+
+const ACTION_SEND_MSG: u32 = 0x0ec3c86d;
+const ACTION_SET_CODE: u32 = 0xad4de08e;
+
+/// One element of an out-action list, as found in the body of a wallet's external
+/// message (`OutList`/`OutListExtended`).
+///
+/// Only the actions common to wallet v4 and v5 are modeled here; v5's
+/// `out_list_extended` header and extension-management actions are left for a
+/// follow-up once that wallet body is implemented.
+#[derive(Clone, Debug, PartialEq)]
+pub enum OutAction {
+    /// `action_send_msg#0ec3c86d mode:(## 8) out_msg:^(MessageRelaxed Any) = OutAction;`
+    SendMsg { mode: u8, message: ArcCell },
+    /// `action_set_code#ad4de08e new_code:^Cell = OutAction;`
+    SetCode { code: ArcCell },
+}
+
+impl TLBObject for OutAction {
+    fn read(parser: &mut CellParser) -> Result<Self, TonCellError> {
+        let opcode = parser.load_u32(32)?;
+        match opcode {
+            ACTION_SEND_MSG => {
+                let mode = parser.load_u32(8)? as u8;
+                let message = parser.next_reference()?;
+                Ok(OutAction::SendMsg { mode, message })
+            }
+            ACTION_SET_CODE => {
+                let code = parser.next_reference()?;
+                Ok(OutAction::SetCode { code })
+            }
+            _ => Err(TonCellError::InternalError(format!(
+                "unsupported out-action opcode: {opcode:#010x}"
+            ))),
+        }
+    }
+
+    fn write_to(&self, dst: &mut CellBuilder) -> Result<(), TonCellError> {
+        match self {
+            OutAction::SendMsg { mode, message } => {
+                dst.store_u32(32, ACTION_SEND_MSG)?;
+                dst.store_u32(8, *mode as u32)?;
+                dst.store_reference(message)?;
+            }
+            OutAction::SetCode { code } => {
+                dst.store_u32(32, ACTION_SET_CODE)?;
+                dst.store_reference(code)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// OutList n:
+//   out_list_empty$_ = OutList 0;
+//   out_list$_ {n:#} prev:^(OutList n) action:OutAction = OutList (n+1);
+//
+// Modeled as `Vec<OutAction>` in execution order (`self[0]` runs first): each node
+// stores the *rest* of the list as `prev` and appends one more action on top, so
+// the first action pushed ends up deepest in the ref chain and the whole list is
+// read back by unwinding `prev` before appending the current node's action.
+impl TLBObject for Vec<OutAction> {
+    fn read(parser: &mut CellParser) -> Result<Self, TonCellError> {
+        let prev = match parser.next_reference() {
+            Ok(prev) => prev,
+            Err(_) => return Ok(Vec::new()),
+        };
+        let mut actions: Vec<OutAction> = prev.parser().load_tlb()?;
+        actions.push(OutAction::read(parser)?);
+        Ok(actions)
+    }
+
+    fn write_to(&self, dst: &mut CellBuilder) -> Result<(), TonCellError> {
+        match self.split_last() {
+            None => Ok(()),
+            Some((last, rest)) => {
+                let mut prev_builder = CellBuilder::new();
+                rest.to_vec().write_to(&mut prev_builder)?;
+                dst.store_reference(&Arc::new(prev_builder.build()?))?;
+                last.write_to(dst)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cell::CellBuilder;
+    use crate::test_support::send_msg;
+    use crate::tlb_types::primitives::out_list::OutAction;
+
+    #[test]
+    fn test_out_list_round_trip() -> anyhow::Result<()> {
+        let actions = vec![send_msg(1), send_msg(3), send_msg(0)];
+        let cell = CellBuilder::new().store_tlb(&actions)?.build()?;
+        let parsed: Vec<OutAction> = cell.parser().load_tlb()?;
+        assert_eq!(actions, parsed);
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_out_list() -> anyhow::Result<()> {
+        let actions: Vec<OutAction> = Vec::new();
+        let cell = CellBuilder::new().store_tlb(&actions)?.build()?;
+        assert_eq!(cell.bit_len(), 0);
+        let parsed: Vec<OutAction> = cell.parser().load_tlb()?;
+        assert_eq!(actions, parsed);
+        Ok(())
+    }
+
+    #[test]
+    fn test_out_list_nesting_order() -> anyhow::Result<()> {
+        // the first action pushed (mode 1) must end up deepest in the ref chain.
+        let actions = vec![send_msg(1), send_msg(2)];
+        let cell = CellBuilder::new().store_tlb(&actions)?.build()?;
+        let prev_actions: Vec<OutAction> = cell.reference(0)?.parser().load_tlb()?;
+        assert_eq!(prev_actions, vec![send_msg(1)]);
+        Ok(())
+    }
+}